@@ -9,6 +9,7 @@
 
 pub use crate::sys::CGPathRef as SysCGPathRef;
 
+use crate::base::CGFloat;
 use crate::geometry::{CGAffineTransform, CGPoint, CGRect};
 use core::ffi::c_void;
 use core_foundation::base::{CFRelease, CFRetain, CFTypeID};
@@ -65,6 +66,331 @@ impl CGPath {
     }
 }
 
+impl CGPathRef {
+    /// Returns whether `point` lies inside the area painted by filling the
+    /// path. When `even_odd` is set the even-odd rule is used, otherwise the
+    /// non-zero winding rule, matching `fill-rule`/`clip-rule` semantics.
+    pub fn contains_point(
+        &self,
+        point: CGPoint,
+        even_odd: bool,
+        transform: Option<&CGAffineTransform>,
+    ) -> bool {
+        unsafe { CGPathContainsPoint(self.as_ptr(), transform_ptr(transform), point, even_odd) }
+    }
+
+    /// The tight bounding box that encloses the path, accounting for the
+    /// geometry of any curves.
+    pub fn bounding_box(&self) -> CGRect {
+        unsafe { CGPathGetBoundingBox(self.as_ptr()) }
+    }
+
+    /// The bounding box of the path's control points. This is cheaper to
+    /// compute than [`bounding_box`](Self::bounding_box) but may be larger.
+    pub fn path_bounding_box(&self) -> CGRect {
+        unsafe { CGPathGetPathBoundingBox(self.as_ptr()) }
+    }
+
+    /// Returns whether the path contains no elements.
+    pub fn is_empty(&self) -> bool {
+        unsafe { CGPathIsEmpty(self.as_ptr()) }
+    }
+
+    /// The point at the end of the most recently added path element.
+    pub fn current_point(&self) -> CGPoint {
+        unsafe { CGPathGetCurrentPoint(self.as_ptr()) }
+    }
+
+    /// Returns a new path outlining the region that would be painted by
+    /// stroking this path with the given pen parameters.
+    pub fn stroked_copy(
+        &self,
+        line_width: CGFloat,
+        line_cap: CGLineCap,
+        line_join: CGLineJoin,
+        miter_limit: CGFloat,
+        transform: Option<&CGAffineTransform>,
+    ) -> CGPath {
+        unsafe {
+            CGPath::from_ptr(CGPathCreateCopyByStrokingPath(
+                self.as_ptr(),
+                transform_ptr(transform),
+                line_width,
+                line_cap,
+                line_join,
+                miter_limit,
+            ))
+        }
+    }
+
+    /// Returns a new path formed by dashing this path with the given phase
+    /// and dash `lengths`.
+    pub fn dashed_copy(
+        &self,
+        phase: CGFloat,
+        lengths: &[CGFloat],
+        transform: Option<&CGAffineTransform>,
+    ) -> CGPath {
+        unsafe {
+            CGPath::from_ptr(CGPathCreateCopyByDashingPath(
+                self.as_ptr(),
+                transform_ptr(transform),
+                phase,
+                lengths.as_ptr(),
+                lengths.len(),
+            ))
+        }
+    }
+
+    /// Returns whether the two paths are structurally equal, i.e. describe
+    /// the same sequence of elements.
+    pub fn equal_to(&self, other: &CGPath) -> bool {
+        unsafe { CGPathEqualToPath(self.as_ptr(), other.as_ptr()) }
+    }
+
+    /// Returns a new path formed by applying `transform` to this path.
+    pub fn transformed_copy(&self, transform: &CGAffineTransform) -> CGPath {
+        unsafe {
+            CGPath::from_ptr(CGPathCreateCopyByTransformingPath(
+                self.as_ptr(),
+                transform as *const CGAffineTransform,
+            ))
+        }
+    }
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CGLineCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CGLineJoin {
+    Miter = 0,
+    Round = 1,
+    Bevel = 2,
+}
+
+impl PartialEq for CGPathRef {
+    fn eq(&self, other: &CGPathRef) -> bool {
+        unsafe { CGPathEqualToPath(self.as_ptr(), other.as_ptr()) }
+    }
+}
+
+impl PartialEq for CGPath {
+    fn eq(&self, other: &CGPath) -> bool {
+        (**self).eq(&**other)
+    }
+}
+
+foreign_type! {
+    #[doc(hidden)]
+    pub unsafe type CGMutablePath {
+        type CType = crate::sys::CGPath;
+        fn drop = |p| CFRelease(p as *mut _);
+        fn clone = |p| CFRetain(p as *const _) as *mut _;
+    }
+}
+
+impl CGMutablePath {
+    /// Creates a new, empty mutable path.
+    pub fn new() -> CGMutablePath {
+        unsafe { CGMutablePath::from_ptr(CGPathCreateMutable()) }
+    }
+
+    /// Creates a mutable copy of an existing path.
+    pub fn from_path(path: &CGPath) -> CGMutablePath {
+        unsafe { CGMutablePath::from_ptr(CGPathCreateMutableCopy(path.as_ptr())) }
+    }
+
+    /// Returns this path as an immutable reference, suitable for passing to
+    /// `CGContext`, `CTFrame`, and the geometry queries on `CGPath`.
+    pub fn as_immutable(&self) -> &CGPathRef {
+        unsafe { CGPathRef::from_ptr(self.as_ptr()) }
+    }
+
+    pub fn move_to_point(&mut self, transform: Option<&CGAffineTransform>, x: CGFloat, y: CGFloat) {
+        unsafe { CGPathMoveToPoint(self.as_ptr(), transform_ptr(transform), x, y) }
+    }
+
+    pub fn add_line_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        x: CGFloat,
+        y: CGFloat,
+    ) {
+        unsafe { CGPathAddLineToPoint(self.as_ptr(), transform_ptr(transform), x, y) }
+    }
+
+    pub fn add_curve_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        cp1x: CGFloat,
+        cp1y: CGFloat,
+        cp2x: CGFloat,
+        cp2y: CGFloat,
+        x: CGFloat,
+        y: CGFloat,
+    ) {
+        unsafe {
+            CGPathAddCurveToPoint(
+                self.as_ptr(),
+                transform_ptr(transform),
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            )
+        }
+    }
+
+    pub fn add_quad_curve_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        cpx: CGFloat,
+        cpy: CGFloat,
+        x: CGFloat,
+        y: CGFloat,
+    ) {
+        unsafe {
+            CGPathAddQuadCurveToPoint(self.as_ptr(), transform_ptr(transform), cpx, cpy, x, y)
+        }
+    }
+
+    pub fn close_subpath(&mut self) {
+        unsafe { CGPathCloseSubpath(self.as_ptr()) }
+    }
+
+    pub fn add_rect(&mut self, transform: Option<&CGAffineTransform>, rect: CGRect) {
+        unsafe { CGPathAddRect(self.as_ptr(), transform_ptr(transform), rect) }
+    }
+
+    pub fn add_rects(&mut self, transform: Option<&CGAffineTransform>, rects: &[CGRect]) {
+        unsafe {
+            CGPathAddRects(
+                self.as_ptr(),
+                transform_ptr(transform),
+                rects.as_ptr(),
+                rects.len(),
+            )
+        }
+    }
+
+    pub fn add_lines(&mut self, transform: Option<&CGAffineTransform>, points: &[CGPoint]) {
+        unsafe {
+            CGPathAddLines(
+                self.as_ptr(),
+                transform_ptr(transform),
+                points.as_ptr(),
+                points.len(),
+            )
+        }
+    }
+
+    pub fn add_arc(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        center: CGPoint,
+        radius: CGFloat,
+        start_angle: CGFloat,
+        end_angle: CGFloat,
+        clockwise: bool,
+    ) {
+        unsafe {
+            CGPathAddArc(
+                self.as_ptr(),
+                transform_ptr(transform),
+                center.x,
+                center.y,
+                radius,
+                start_angle,
+                end_angle,
+                clockwise,
+            )
+        }
+    }
+
+    pub fn add_arc_to_point(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        x1: CGFloat,
+        y1: CGFloat,
+        x2: CGFloat,
+        y2: CGFloat,
+        radius: CGFloat,
+    ) {
+        unsafe {
+            CGPathAddArcToPoint(self.as_ptr(), transform_ptr(transform), x1, y1, x2, y2, radius)
+        }
+    }
+
+    pub fn add_ellipse_in_rect(&mut self, transform: Option<&CGAffineTransform>, rect: CGRect) {
+        unsafe { CGPathAddEllipseInRect(self.as_ptr(), transform_ptr(transform), rect) }
+    }
+
+    pub fn add_relative_arc(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        center: CGPoint,
+        radius: CGFloat,
+        start_angle: CGFloat,
+        delta: CGFloat,
+    ) {
+        unsafe {
+            CGPathAddRelativeArc(
+                self.as_ptr(),
+                transform_ptr(transform),
+                center.x,
+                center.y,
+                radius,
+                start_angle,
+                delta,
+            )
+        }
+    }
+
+    pub fn add_rounded_rect(
+        &mut self,
+        transform: Option<&CGAffineTransform>,
+        rect: CGRect,
+        corner_width: CGFloat,
+        corner_height: CGFloat,
+    ) {
+        unsafe {
+            CGPathAddRoundedRect(
+                self.as_ptr(),
+                transform_ptr(transform),
+                rect,
+                corner_width,
+                corner_height,
+            )
+        }
+    }
+
+    pub fn add_path(&mut self, transform: Option<&CGAffineTransform>, other: &CGPath) {
+        unsafe { CGPathAddPath(self.as_ptr(), transform_ptr(transform), other.as_ptr()) }
+    }
+}
+
+impl Default for CGMutablePath {
+    fn default() -> CGMutablePath {
+        CGMutablePath::new()
+    }
+}
+
+fn transform_ptr(transform: Option<&CGAffineTransform>) -> *const CGAffineTransform {
+    match transform {
+        None => ptr::null(),
+        Some(transform) => transform as *const CGAffineTransform,
+    }
+}
+
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CGPathElementType {
@@ -133,4 +459,126 @@ extern "C" {
     ) -> crate::sys::CGPathRef;
     fn CGPathApply(path: crate::sys::CGPathRef, info: *mut c_void, function: CGPathApplierFunction);
     fn CGPathGetTypeID() -> CFTypeID;
+    fn CGPathContainsPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        point: CGPoint,
+        eo_fill: bool,
+    ) -> bool;
+    fn CGPathGetBoundingBox(path: crate::sys::CGPathRef) -> CGRect;
+    fn CGPathGetPathBoundingBox(path: crate::sys::CGPathRef) -> CGRect;
+    fn CGPathIsEmpty(path: crate::sys::CGPathRef) -> bool;
+    fn CGPathGetCurrentPoint(path: crate::sys::CGPathRef) -> CGPoint;
+    fn CGPathCreateCopyByStrokingPath(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        line_width: CGFloat,
+        line_cap: CGLineCap,
+        line_join: CGLineJoin,
+        miter_limit: CGFloat,
+    ) -> crate::sys::CGPathRef;
+    fn CGPathCreateCopyByDashingPath(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        phase: CGFloat,
+        lengths: *const CGFloat,
+        count: usize,
+    ) -> crate::sys::CGPathRef;
+    fn CGPathCreateCopyByTransformingPath(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+    ) -> crate::sys::CGPathRef;
+    fn CGPathEqualToPath(path1: crate::sys::CGPathRef, path2: crate::sys::CGPathRef) -> bool;
+
+    fn CGPathCreateMutable() -> crate::sys::CGPathRef;
+    fn CGPathCreateMutableCopy(path: crate::sys::CGPathRef) -> crate::sys::CGPathRef;
+    fn CGPathMoveToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        x: CGFloat,
+        y: CGFloat,
+    );
+    fn CGPathAddLineToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        x: CGFloat,
+        y: CGFloat,
+    );
+    fn CGPathAddCurveToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        cp1x: CGFloat,
+        cp1y: CGFloat,
+        cp2x: CGFloat,
+        cp2y: CGFloat,
+        x: CGFloat,
+        y: CGFloat,
+    );
+    fn CGPathAddQuadCurveToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        cpx: CGFloat,
+        cpy: CGFloat,
+        x: CGFloat,
+        y: CGFloat,
+    );
+    fn CGPathCloseSubpath(path: crate::sys::CGPathRef);
+    fn CGPathAddRect(path: crate::sys::CGPathRef, m: *const CGAffineTransform, rect: CGRect);
+    fn CGPathAddRects(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        rects: *const CGRect,
+        count: usize,
+    );
+    fn CGPathAddLines(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        points: *const CGPoint,
+        count: usize,
+    );
+    fn CGPathAddArc(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        x: CGFloat,
+        y: CGFloat,
+        radius: CGFloat,
+        start_angle: CGFloat,
+        end_angle: CGFloat,
+        clockwise: bool,
+    );
+    fn CGPathAddArcToPoint(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        x1: CGFloat,
+        y1: CGFloat,
+        x2: CGFloat,
+        y2: CGFloat,
+        radius: CGFloat,
+    );
+    fn CGPathAddEllipseInRect(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        rect: CGRect,
+    );
+    fn CGPathAddRelativeArc(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        x: CGFloat,
+        y: CGFloat,
+        radius: CGFloat,
+        start_angle: CGFloat,
+        delta: CGFloat,
+    );
+    fn CGPathAddRoundedRect(
+        path: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        rect: CGRect,
+        corner_width: CGFloat,
+        corner_height: CGFloat,
+    );
+    fn CGPathAddPath(
+        path1: crate::sys::CGPathRef,
+        m: *const CGAffineTransform,
+        path2: crate::sys::CGPathRef,
+    );
 }